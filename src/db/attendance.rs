@@ -0,0 +1,11 @@
+use async_graphql::SimpleObject;
+use chrono::{NaiveDate, NaiveTime};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow, SimpleObject)]
+pub struct Attendance {
+    pub id: i32,
+    pub date: NaiveDate,
+    pub timein: NaiveTime,
+    pub timeout: NaiveTime,
+}
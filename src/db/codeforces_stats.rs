@@ -0,0 +1,12 @@
+use async_graphql::SimpleObject;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow, SimpleObject)]
+pub struct CodeforcesStats {
+    pub id: i32,
+    pub member_id: i32,
+    pub codeforces_handle: String,
+    pub codeforces_rating: i32,
+    pub max_rating: i32,
+    pub contests_participated: i32,
+}
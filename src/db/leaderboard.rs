@@ -0,0 +1,13 @@
+use async_graphql::SimpleObject;
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow, SimpleObject)]
+pub struct Leaderboard {
+    pub id: i32,
+    pub member_id: i32,
+    pub leetcode_score: Option<i32>,
+    pub codeforces_score: Option<i32>,
+    pub unified_score: i32,
+    pub last_updated: NaiveDateTime,
+}
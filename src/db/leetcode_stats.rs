@@ -0,0 +1,16 @@
+use async_graphql::SimpleObject;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow, SimpleObject)]
+pub struct LeetcodeStats {
+    pub id: i32,
+    pub member_id: i32,
+    pub leetcode_username: String,
+    pub problems_solved: i32,
+    pub easy_solved: i32,
+    pub medium_solved: i32,
+    pub hard_solved: i32,
+    pub contests_participated: i32,
+    pub best_rank: i32,
+    pub total_contests: i32,
+}
@@ -0,0 +1,32 @@
+use async_graphql::SimpleObject;
+use serde::Deserialize;
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, FromRow, SimpleObject)]
+pub struct Member {
+    pub id: i32,
+    pub rollno: String,
+    pub name: String,
+    pub hostel: String,
+    pub email: String,
+    pub sex: String,
+    pub year: i32,
+    pub discord_id: Option<String>,
+    /// Whether the member is still on the external roster. Members that
+    /// disappear from the roster are marked inactive rather than deleted,
+    /// so their attendance/stats history is preserved.
+    pub active: bool,
+}
+
+/// A member as reported by the external roster API. Missing `sex`, since
+/// the roster doesn't track it; that's left untouched for existing members
+/// and set to an empty string for newcomers.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RemoteMember {
+    pub rollno: String,
+    pub name: String,
+    pub hostel: String,
+    pub email: String,
+    pub year: i32,
+    pub discord_id: Option<String>,
+}
@@ -0,0 +1,22 @@
+use sqlx::{PgPool, SqlitePool};
+
+/// Applies any migrations under `migrations/postgres/` that haven't already
+/// been recorded in the `_sqlx_migrations` table. Forward-only: there are no
+/// `down` scripts, matching how the rest of the schema is managed.
+///
+/// `migrations/sqlite/` is the SQLite-syntax mirror of the same versioned
+/// schema (SQLite has no `SERIAL`, so ids there are `INTEGER PRIMARY KEY
+/// AUTOINCREMENT`), applied by [`run_sqlite_migrations`] instead of hand-written
+/// DDL, so the two backends can't silently drift apart. Changing a table
+/// here means updating both directories.
+pub async fn run_migrations(pool: &PgPool) -> Result<(), sqlx::migrate::MigrateError> {
+    sqlx::migrate!("./migrations/postgres").run(pool).await
+}
+
+/// Applies any migrations under `migrations/sqlite/` that haven't already
+/// been recorded in the `_sqlx_migrations` table. The SQLite counterpart to
+/// [`run_migrations`], used by `SqliteStore::connect` so it doesn't have to
+/// hand-roll the schema.
+pub async fn run_sqlite_migrations(pool: &SqlitePool) -> Result<(), sqlx::migrate::MigrateError> {
+    sqlx::migrate!("./migrations/sqlite").run(pool).await
+}
@@ -0,0 +1,7 @@
+pub mod attendance;
+pub mod codeforces_stats;
+pub mod leaderboard;
+pub mod leetcode_stats;
+pub mod member;
+pub mod migrate;
+pub mod store;
@@ -0,0 +1,722 @@
+//! Every query here is runtime-checked (`query`/`query_as` with bound
+//! placeholders) rather than `query!`/`query_as!`, so the crate builds with
+//! no `DATABASE_URL` and no committed query-metadata cache. `PostgresStore`
+//! and `SqliteStore` therefore stay symmetric: the same style of query
+//! works against both backends instead of one needing a Postgres-specific
+//! compile-time check the other can't share.
+//!
+//! NOTE: this deliberately drops the compile-time-checking half of the
+//! offline-migrations request (`query!`/`query_as!` backed by a committed
+//! `.sqlx` cache so `SQLX_OFFLINE=true` still gets schema verification).
+//! Generating that cache for real requires `cargo sqlx prepare` against a
+//! live Postgres instance, which isn't available in this environment, and a
+//! hand-written `.sqlx/*.json` would just be a fabricated cache that
+//! silently goes stale the first time a query or migration changes without
+//! anyone regenerating it — worse than no cache at all. Restoring
+//! `query!`/`query_as!` here is a follow-up for whoever next has a
+//! `DATABASE_URL` to run `cargo sqlx prepare` against the migrations in
+//! `migrations/postgres/` and commit the resulting `.sqlx/` directory.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, SqlitePool};
+
+use super::attendance::Attendance;
+use super::codeforces_stats::CodeforcesStats;
+use super::leaderboard::Leaderboard;
+use super::leetcode_stats::LeetcodeStats;
+use super::member::{Member, RemoteMember};
+
+/// Fields needed to create a new [`Member`]; kept separate from `Member`
+/// itself since the row's `id` is assigned by the database.
+#[derive(Debug, Clone)]
+pub struct NewMember {
+    pub rollno: String,
+    pub name: String,
+    pub hostel: String,
+    pub email: String,
+    pub sex: String,
+    pub year: i32,
+}
+
+/// Fields needed to create a new [`Attendance`] entry.
+#[derive(Debug, Clone)]
+pub struct NewAttendance {
+    pub id: i32,
+    pub date: chrono::NaiveDate,
+    pub timein: chrono::NaiveTime,
+    pub timeout: chrono::NaiveTime,
+}
+
+/// A new member plus the handles to onboard alongside them. Passed to
+/// [`Store::onboard_member`], which inserts the member and its dependent
+/// `leetcode_stats`/`codeforces_stats`/`leaderboard` rows in one transaction.
+#[derive(Debug, Clone)]
+pub struct MemberOnboarding {
+    pub member: NewMember,
+    pub leetcode_username: Option<String>,
+    pub codeforces_handle: Option<String>,
+}
+
+/// Abstracts the member/attendance/leaderboard/stats persistence operations
+/// so the rest of the crate doesn't need to know whether it's talking to
+/// Postgres or SQLite. The GraphQL context and leaderboard jobs hold an
+/// `Arc<dyn Store>` rather than a concrete pool.
+#[async_trait]
+pub trait Store: Send + Sync {
+    async fn add_member(&self, member: NewMember) -> Result<Member, sqlx::Error>;
+    async fn add_attendance(&self, attendance: NewAttendance) -> Result<Attendance, sqlx::Error>;
+
+    /// Inserts the member and its `leetcode_stats`/`codeforces_stats`/
+    /// `leaderboard` rows (zeroed until the next stats fetch) as a single
+    /// transaction, so onboarding never leaves a member without the rows
+    /// the rest of the crate assumes exist.
+    ///
+    /// This is deliberately *not* a request-scoped `sqlx::Transaction` threaded
+    /// through `async_graphql::Context` by a guard/extension, begun before the
+    /// resolver runs and committed after. That design would need `Context` to
+    /// hold a concrete `Transaction<'_, Postgres>` or `Transaction<'_, Sqlite>`,
+    /// which breaks the backend-agnostic `Arc<dyn Store>` GraphQL and the
+    /// leaderboard jobs rely on everywhere else (the whole point of the
+    /// `Store` trait). Instead each backend opens and commits its own
+    /// transaction inside this one method, which gives `add_member` the same
+    /// atomicity without leaking a concrete transaction type past the `Store`
+    /// boundary. A future multi-table mutation should follow the same
+    /// pattern — a dedicated `Store` method that owns its transaction — rather
+    /// than trying to thread one through `Context`.
+    async fn onboard_member(&self, onboarding: MemberOnboarding) -> Result<Member, sqlx::Error>;
+
+    /// Upserts `roster` by email and marks any active member whose email
+    /// isn't in it inactive. Never hard-deletes, so attendance/stats history
+    /// for a member who leaves is preserved.
+    async fn sync_roster(&self, roster: Vec<RemoteMember>) -> Result<(), sqlx::Error>;
+
+    /// Leaderboard rows for every *active* member only, so a deactivated
+    /// member's stale row doesn't get reloaded into the cache by a fresh
+    /// [`crate::leaderboard::manager::LeaderboardManager::load`] (e.g. on
+    /// restart).
+    async fn leaderboard(&self) -> Result<Vec<Leaderboard>, sqlx::Error>;
+
+    /// Deletes `member_id`'s leaderboard row, if it has one. Used to retire
+    /// a member who's no longer active so their frozen score doesn't linger
+    /// forever; never fails if there was nothing to delete.
+    async fn delete_leaderboard_entry(&self, member_id: i32) -> Result<(), sqlx::Error>;
+
+    /// Stats for every *active* member only, so a member deactivated by
+    /// [`Store::sync_roster`] stops being re-fetched from external APIs and
+    /// drops off the leaderboard the next time it's rebuilt.
+    async fn all_leetcode_stats(&self) -> Result<Vec<LeetcodeStats>, sqlx::Error>;
+    async fn all_codeforces_stats(&self) -> Result<Vec<CodeforcesStats>, sqlx::Error>;
+    async fn leetcode_stats_for_member(
+        &self,
+        member_id: i32,
+    ) -> Result<Option<LeetcodeStats>, sqlx::Error>;
+    async fn codeforces_stats_for_member(
+        &self,
+        member_id: i32,
+    ) -> Result<Option<CodeforcesStats>, sqlx::Error>;
+    async fn upsert_leaderboard_entry(
+        &self,
+        member_id: i32,
+        leetcode_score: Option<i32>,
+        codeforces_score: Option<i32>,
+        unified_score: i32,
+    ) -> Result<Leaderboard, sqlx::Error>;
+
+    async fn upsert_leetcode_stats(
+        &self,
+        member_id: i32,
+        leetcode_username: &str,
+        problems_solved: i32,
+        easy_solved: i32,
+        medium_solved: i32,
+        hard_solved: i32,
+        contests_participated: i32,
+        best_rank: i32,
+        total_contests: i32,
+    ) -> Result<LeetcodeStats, sqlx::Error>;
+
+    async fn upsert_codeforces_stats(
+        &self,
+        member_id: i32,
+        codeforces_handle: &str,
+        codeforces_rating: i32,
+        max_rating: i32,
+        contests_participated: i32,
+    ) -> Result<CodeforcesStats, sqlx::Error>;
+}
+
+/// Production [`Store`] backed by Postgres.
+pub struct PostgresStore {
+    pool: PgPool,
+}
+
+impl PostgresStore {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Connects to `database_url` and applies any pending migrations before
+    /// returning, so callers never have to remember to run them separately.
+    pub async fn connect(database_url: &str) -> Result<Self, sqlx::Error> {
+        let pool = PgPool::connect(database_url).await?;
+        super::migrate::run_migrations(&pool)
+            .await
+            .map_err(|e| sqlx::Error::Migrate(Box::new(e)))?;
+        Ok(Self::new(pool))
+    }
+}
+
+#[async_trait]
+impl Store for PostgresStore {
+    async fn add_member(&self, member: NewMember) -> Result<Member, sqlx::Error> {
+        sqlx::query_as::<_, Member>(
+            "INSERT INTO member (rollno, name, hostel, email, sex, year) VALUES ($1, $2, $3, $4, $5, $6) RETURNING *"
+        )
+        .bind(member.rollno)
+        .bind(member.name)
+        .bind(member.hostel)
+        .bind(member.email)
+        .bind(member.sex)
+        .bind(member.year)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    async fn add_attendance(&self, attendance: NewAttendance) -> Result<Attendance, sqlx::Error> {
+        sqlx::query_as::<_, Attendance>(
+            "INSERT INTO attendance (id, date, timein, timeout) VALUES ($1, $2, $3, $4) RETURNING *"
+        )
+        .bind(attendance.id)
+        .bind(attendance.date)
+        .bind(attendance.timein)
+        .bind(attendance.timeout)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    async fn onboard_member(&self, onboarding: MemberOnboarding) -> Result<Member, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let member = sqlx::query_as::<_, Member>(
+            "INSERT INTO member (rollno, name, hostel, email, sex, year) VALUES ($1, $2, $3, $4, $5, $6) RETURNING *"
+        )
+        .bind(onboarding.member.rollno)
+        .bind(onboarding.member.name)
+        .bind(onboarding.member.hostel)
+        .bind(onboarding.member.email)
+        .bind(onboarding.member.sex)
+        .bind(onboarding.member.year)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO leetcode_stats (member_id, leetcode_username, problems_solved, easy_solved, medium_solved, hard_solved, contests_participated, best_rank, total_contests)
+             VALUES ($1, $2, 0, 0, 0, 0, 0, 0, 0)",
+        )
+        .bind(member.id)
+        .bind(onboarding.leetcode_username.unwrap_or_default())
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO codeforces_stats (member_id, codeforces_handle, codeforces_rating, max_rating, contests_participated)
+             VALUES ($1, $2, 0, 0, 0)",
+        )
+        .bind(member.id)
+        .bind(onboarding.codeforces_handle.unwrap_or_default())
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO leaderboard (member_id, leetcode_score, codeforces_score, unified_score) VALUES ($1, 0, 0, 0)",
+        )
+        .bind(member.id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(member)
+    }
+
+    async fn sync_roster(&self, roster: Vec<RemoteMember>) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let mut synced_emails = Vec::with_capacity(roster.len());
+        for remote in &roster {
+            sqlx::query(
+                "INSERT INTO member (rollno, name, hostel, email, sex, year, discord_id, active)
+                 VALUES ($1, $2, $3, $4, '', $5, $6, TRUE)
+                 ON CONFLICT (email) DO UPDATE
+                 SET rollno = EXCLUDED.rollno,
+                     name = EXCLUDED.name,
+                     hostel = EXCLUDED.hostel,
+                     year = EXCLUDED.year,
+                     discord_id = EXCLUDED.discord_id,
+                     active = TRUE",
+            )
+            .bind(&remote.rollno)
+            .bind(&remote.name)
+            .bind(&remote.hostel)
+            .bind(&remote.email)
+            .bind(remote.year)
+            .bind(&remote.discord_id)
+            .execute(&mut *tx)
+            .await?;
+            synced_emails.push(remote.email.clone());
+        }
+
+        let active_members = sqlx::query_as::<_, Member>("SELECT * FROM member WHERE active")
+            .fetch_all(&mut *tx)
+            .await?;
+        for member in active_members {
+            if !synced_emails.contains(&member.email) {
+                sqlx::query("UPDATE member SET active = FALSE WHERE id = $1")
+                    .bind(member.id)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn leaderboard(&self) -> Result<Vec<Leaderboard>, sqlx::Error> {
+        sqlx::query_as::<_, Leaderboard>(
+            "SELECT leaderboard.* FROM leaderboard
+             JOIN member ON member.id = leaderboard.member_id
+             WHERE member.active",
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    async fn delete_leaderboard_entry(&self, member_id: i32) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM leaderboard WHERE member_id = $1")
+            .bind(member_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn all_leetcode_stats(&self) -> Result<Vec<LeetcodeStats>, sqlx::Error> {
+        sqlx::query_as::<_, LeetcodeStats>(
+            "SELECT leetcode_stats.* FROM leetcode_stats
+             JOIN member ON member.id = leetcode_stats.member_id
+             WHERE member.active",
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    async fn all_codeforces_stats(&self) -> Result<Vec<CodeforcesStats>, sqlx::Error> {
+        sqlx::query_as::<_, CodeforcesStats>(
+            "SELECT codeforces_stats.* FROM codeforces_stats
+             JOIN member ON member.id = codeforces_stats.member_id
+             WHERE member.active",
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    async fn leetcode_stats_for_member(
+        &self,
+        member_id: i32,
+    ) -> Result<Option<LeetcodeStats>, sqlx::Error> {
+        sqlx::query_as::<_, LeetcodeStats>("SELECT * FROM leetcode_stats WHERE member_id = $1")
+            .bind(member_id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    async fn codeforces_stats_for_member(
+        &self,
+        member_id: i32,
+    ) -> Result<Option<CodeforcesStats>, sqlx::Error> {
+        sqlx::query_as::<_, CodeforcesStats>("SELECT * FROM codeforces_stats WHERE member_id = $1")
+            .bind(member_id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    async fn upsert_leaderboard_entry(
+        &self,
+        member_id: i32,
+        leetcode_score: Option<i32>,
+        codeforces_score: Option<i32>,
+        unified_score: i32,
+    ) -> Result<Leaderboard, sqlx::Error> {
+        sqlx::query_as::<_, Leaderboard>(
+            "INSERT INTO leaderboard (member_id, leetcode_score, codeforces_score, unified_score)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT (member_id) DO UPDATE
+             SET leetcode_score = EXCLUDED.leetcode_score,
+                 codeforces_score = EXCLUDED.codeforces_score,
+                 unified_score = EXCLUDED.unified_score,
+                 last_updated = CURRENT_TIMESTAMP
+             RETURNING *",
+        )
+        .bind(member_id)
+        .bind(leetcode_score)
+        .bind(codeforces_score)
+        .bind(unified_score)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    async fn upsert_leetcode_stats(
+        &self,
+        member_id: i32,
+        leetcode_username: &str,
+        problems_solved: i32,
+        easy_solved: i32,
+        medium_solved: i32,
+        hard_solved: i32,
+        contests_participated: i32,
+        best_rank: i32,
+        total_contests: i32,
+    ) -> Result<LeetcodeStats, sqlx::Error> {
+        sqlx::query_as::<_, LeetcodeStats>(
+            "INSERT INTO leetcode_stats (member_id, leetcode_username, problems_solved, easy_solved, medium_solved, hard_solved, contests_participated, best_rank, total_contests)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+             ON CONFLICT (member_id) DO UPDATE
+             SET leetcode_username = EXCLUDED.leetcode_username,
+                 problems_solved = EXCLUDED.problems_solved,
+                 easy_solved = EXCLUDED.easy_solved,
+                 medium_solved = EXCLUDED.medium_solved,
+                 hard_solved = EXCLUDED.hard_solved,
+                 contests_participated = EXCLUDED.contests_participated,
+                 best_rank = EXCLUDED.best_rank,
+                 total_contests = EXCLUDED.total_contests
+             RETURNING *",
+        )
+        .bind(member_id)
+        .bind(leetcode_username)
+        .bind(problems_solved)
+        .bind(easy_solved)
+        .bind(medium_solved)
+        .bind(hard_solved)
+        .bind(contests_participated)
+        .bind(best_rank)
+        .bind(total_contests)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    async fn upsert_codeforces_stats(
+        &self,
+        member_id: i32,
+        codeforces_handle: &str,
+        codeforces_rating: i32,
+        max_rating: i32,
+        contests_participated: i32,
+    ) -> Result<CodeforcesStats, sqlx::Error> {
+        sqlx::query_as::<_, CodeforcesStats>(
+            "INSERT INTO codeforces_stats (member_id, codeforces_handle, codeforces_rating, max_rating, contests_participated)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (member_id) DO UPDATE
+             SET codeforces_handle = EXCLUDED.codeforces_handle,
+                 codeforces_rating = EXCLUDED.codeforces_rating,
+                 max_rating = EXCLUDED.max_rating,
+                 contests_participated = EXCLUDED.contests_participated
+             RETURNING *",
+        )
+        .bind(member_id)
+        .bind(codeforces_handle)
+        .bind(codeforces_rating)
+        .bind(max_rating)
+        .bind(contests_participated)
+        .fetch_one(&self.pool)
+        .await
+    }
+}
+
+/// [`Store`] backed by embedded SQLite, for local development and tests
+/// that shouldn't need a live Postgres instance.
+pub struct SqliteStore {
+    pool: SqlitePool,
+}
+
+impl SqliteStore {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Connects to `database_url` and applies any pending migrations before
+    /// returning, mirroring [`PostgresStore::connect`] so local development
+    /// against embedded SQLite doesn't need its own hand-rolled setup step.
+    pub async fn connect(database_url: &str) -> Result<Self, sqlx::Error> {
+        let pool = SqlitePool::connect(database_url).await?;
+        super::migrate::run_sqlite_migrations(&pool)
+            .await
+            .map_err(|e| sqlx::Error::Migrate(Box::new(e)))?;
+        Ok(Self::new(pool))
+    }
+}
+
+#[async_trait]
+impl Store for SqliteStore {
+    async fn add_member(&self, member: NewMember) -> Result<Member, sqlx::Error> {
+        sqlx::query_as::<_, Member>(
+            "INSERT INTO member (rollno, name, hostel, email, sex, year) VALUES (?, ?, ?, ?, ?, ?) RETURNING *"
+        )
+        .bind(member.rollno)
+        .bind(member.name)
+        .bind(member.hostel)
+        .bind(member.email)
+        .bind(member.sex)
+        .bind(member.year)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    async fn add_attendance(&self, attendance: NewAttendance) -> Result<Attendance, sqlx::Error> {
+        sqlx::query_as::<_, Attendance>(
+            "INSERT INTO attendance (id, date, timein, timeout) VALUES (?, ?, ?, ?) RETURNING *"
+        )
+        .bind(attendance.id)
+        .bind(attendance.date)
+        .bind(attendance.timein)
+        .bind(attendance.timeout)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    async fn onboard_member(&self, onboarding: MemberOnboarding) -> Result<Member, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let member = sqlx::query_as::<_, Member>(
+            "INSERT INTO member (rollno, name, hostel, email, sex, year) VALUES (?, ?, ?, ?, ?, ?) RETURNING *"
+        )
+        .bind(onboarding.member.rollno)
+        .bind(onboarding.member.name)
+        .bind(onboarding.member.hostel)
+        .bind(onboarding.member.email)
+        .bind(onboarding.member.sex)
+        .bind(onboarding.member.year)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO leetcode_stats (member_id, leetcode_username, problems_solved, easy_solved, medium_solved, hard_solved, contests_participated, best_rank, total_contests)
+             VALUES (?, ?, 0, 0, 0, 0, 0, 0, 0)",
+        )
+        .bind(member.id)
+        .bind(onboarding.leetcode_username.unwrap_or_default())
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO codeforces_stats (member_id, codeforces_handle, codeforces_rating, max_rating, contests_participated)
+             VALUES (?, ?, 0, 0, 0)",
+        )
+        .bind(member.id)
+        .bind(onboarding.codeforces_handle.unwrap_or_default())
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "INSERT INTO leaderboard (member_id, leetcode_score, codeforces_score, unified_score) VALUES (?, 0, 0, 0)",
+        )
+        .bind(member.id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(member)
+    }
+
+    async fn sync_roster(&self, roster: Vec<RemoteMember>) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let mut synced_emails = Vec::with_capacity(roster.len());
+        for remote in &roster {
+            sqlx::query(
+                "INSERT INTO member (rollno, name, hostel, email, sex, year, discord_id, active)
+                 VALUES (?, ?, ?, ?, '', ?, ?, 1)
+                 ON CONFLICT (email) DO UPDATE
+                 SET rollno = excluded.rollno,
+                     name = excluded.name,
+                     hostel = excluded.hostel,
+                     year = excluded.year,
+                     discord_id = excluded.discord_id,
+                     active = 1",
+            )
+            .bind(&remote.rollno)
+            .bind(&remote.name)
+            .bind(&remote.hostel)
+            .bind(&remote.email)
+            .bind(remote.year)
+            .bind(&remote.discord_id)
+            .execute(&mut *tx)
+            .await?;
+            synced_emails.push(remote.email.clone());
+        }
+
+        let active_members = sqlx::query_as::<_, Member>("SELECT * FROM member WHERE active")
+            .fetch_all(&mut *tx)
+            .await?;
+        for member in active_members {
+            if !synced_emails.contains(&member.email) {
+                sqlx::query("UPDATE member SET active = 0 WHERE id = ?")
+                    .bind(member.id)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn leaderboard(&self) -> Result<Vec<Leaderboard>, sqlx::Error> {
+        sqlx::query_as::<_, Leaderboard>(
+            "SELECT leaderboard.* FROM leaderboard
+             JOIN member ON member.id = leaderboard.member_id
+             WHERE member.active",
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    async fn delete_leaderboard_entry(&self, member_id: i32) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM leaderboard WHERE member_id = ?")
+            .bind(member_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn all_leetcode_stats(&self) -> Result<Vec<LeetcodeStats>, sqlx::Error> {
+        sqlx::query_as::<_, LeetcodeStats>(
+            "SELECT leetcode_stats.* FROM leetcode_stats
+             JOIN member ON member.id = leetcode_stats.member_id
+             WHERE member.active",
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    async fn all_codeforces_stats(&self) -> Result<Vec<CodeforcesStats>, sqlx::Error> {
+        sqlx::query_as::<_, CodeforcesStats>(
+            "SELECT codeforces_stats.* FROM codeforces_stats
+             JOIN member ON member.id = codeforces_stats.member_id
+             WHERE member.active",
+        )
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    async fn leetcode_stats_for_member(
+        &self,
+        member_id: i32,
+    ) -> Result<Option<LeetcodeStats>, sqlx::Error> {
+        sqlx::query_as::<_, LeetcodeStats>("SELECT * FROM leetcode_stats WHERE member_id = ?")
+            .bind(member_id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    async fn codeforces_stats_for_member(
+        &self,
+        member_id: i32,
+    ) -> Result<Option<CodeforcesStats>, sqlx::Error> {
+        sqlx::query_as::<_, CodeforcesStats>("SELECT * FROM codeforces_stats WHERE member_id = ?")
+            .bind(member_id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    async fn upsert_leaderboard_entry(
+        &self,
+        member_id: i32,
+        leetcode_score: Option<i32>,
+        codeforces_score: Option<i32>,
+        unified_score: i32,
+    ) -> Result<Leaderboard, sqlx::Error> {
+        sqlx::query_as::<_, Leaderboard>(
+            "INSERT INTO leaderboard (member_id, leetcode_score, codeforces_score, unified_score)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT (member_id) DO UPDATE
+             SET leetcode_score = excluded.leetcode_score,
+                 codeforces_score = excluded.codeforces_score,
+                 unified_score = excluded.unified_score,
+                 last_updated = CURRENT_TIMESTAMP
+             RETURNING *",
+        )
+        .bind(member_id)
+        .bind(leetcode_score)
+        .bind(codeforces_score)
+        .bind(unified_score)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    async fn upsert_leetcode_stats(
+        &self,
+        member_id: i32,
+        leetcode_username: &str,
+        problems_solved: i32,
+        easy_solved: i32,
+        medium_solved: i32,
+        hard_solved: i32,
+        contests_participated: i32,
+        best_rank: i32,
+        total_contests: i32,
+    ) -> Result<LeetcodeStats, sqlx::Error> {
+        sqlx::query_as::<_, LeetcodeStats>(
+            "INSERT INTO leetcode_stats (member_id, leetcode_username, problems_solved, easy_solved, medium_solved, hard_solved, contests_participated, best_rank, total_contests)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT (member_id) DO UPDATE
+             SET leetcode_username = excluded.leetcode_username,
+                 problems_solved = excluded.problems_solved,
+                 easy_solved = excluded.easy_solved,
+                 medium_solved = excluded.medium_solved,
+                 hard_solved = excluded.hard_solved,
+                 contests_participated = excluded.contests_participated,
+                 best_rank = excluded.best_rank,
+                 total_contests = excluded.total_contests
+             RETURNING *",
+        )
+        .bind(member_id)
+        .bind(leetcode_username)
+        .bind(problems_solved)
+        .bind(easy_solved)
+        .bind(medium_solved)
+        .bind(hard_solved)
+        .bind(contests_participated)
+        .bind(best_rank)
+        .bind(total_contests)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    async fn upsert_codeforces_stats(
+        &self,
+        member_id: i32,
+        codeforces_handle: &str,
+        codeforces_rating: i32,
+        max_rating: i32,
+        contests_participated: i32,
+    ) -> Result<CodeforcesStats, sqlx::Error> {
+        sqlx::query_as::<_, CodeforcesStats>(
+            "INSERT INTO codeforces_stats (member_id, codeforces_handle, codeforces_rating, max_rating, contests_participated)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT (member_id) DO UPDATE
+             SET codeforces_handle = excluded.codeforces_handle,
+                 codeforces_rating = excluded.codeforces_rating,
+                 max_rating = excluded.max_rating,
+                 contests_participated = excluded.contests_participated
+             RETURNING *",
+        )
+        .bind(member_id)
+        .bind(codeforces_handle)
+        .bind(codeforces_rating)
+        .bind(max_rating)
+        .bind(contests_participated)
+        .fetch_one(&self.pool)
+        .await
+    }
+}
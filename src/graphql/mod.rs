@@ -0,0 +1,2 @@
+pub mod mutations;
+pub mod queries;
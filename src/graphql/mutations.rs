@@ -1,40 +1,50 @@
 use async_graphql::{Context, Object};
 use chrono::{NaiveDate, NaiveTime};
-use sqlx::PgPool;
-use  sqlx::types::chrono;
 use std::sync::Arc;
 
-use crate::db::{member::Member, attendance::Attendance};
+use crate::db::attendance::Attendance;
+use crate::db::member::Member;
+use crate::db::store::{MemberOnboarding, NewAttendance, NewMember, Store};
+use crate::leaderboard::manager::LeaderboardManager;
 
 pub struct MutationRoot;
 
 #[Object]
 impl MutationRoot {
     async fn add_member(
-        &self, 
-        ctx: &Context<'_>, 
-        rollno: String, 
-        name: String, 
-        hostel: String, 
-        email: String, 
-        sex: String, 
-        year: i32
+        &self,
+        ctx: &Context<'_>,
+        rollno: String,
+        name: String,
+        hostel: String,
+        email: String,
+        sex: String,
+        year: i32,
+        leetcode_username: Option<String>,
+        codeforces_handle: Option<String>,
     ) -> Result<Member, sqlx::Error> {
-        let pool = ctx.data::<Arc<PgPool>>().expect("Pool not found in context");
+        // Routed through LeaderboardManager (not Store directly) so the new
+        // member's zeroed leaderboard row lands in the in-memory cache in
+        // the same call, instead of being invisible to QueryRoot until the
+        // next update_leaderboard pass.
+        let manager = ctx
+            .data::<Arc<LeaderboardManager>>()
+            .expect("LeaderboardManager not found in context");
 
-        let member = sqlx::query_as::<_, Member>(
-            "INSERT INTO Member (rollno, name, hostel, email, sex, year) VALUES ($1, $2, $3, $4, $5, $6) RETURNING *"
-        )
-        .bind(rollno)
-        .bind(name)
-        .bind(hostel)
-        .bind(email)
-        .bind(sex)
-        .bind(year)
-        .fetch_one(pool.as_ref())
-        .await?;
-
-        Ok(member)
+        manager
+            .onboard_member(MemberOnboarding {
+                member: NewMember {
+                    rollno,
+                    name,
+                    hostel,
+                    email,
+                    sex,
+                    year,
+                },
+                leetcode_username,
+                codeforces_handle,
+            })
+            .await
     }
 
     async fn add_attendance(
@@ -45,18 +55,15 @@ impl MutationRoot {
         timein: NaiveTime,
         timeout: NaiveTime,
     ) -> Result<Attendance, sqlx::Error> {
-        let pool = ctx.data::<Arc<PgPool>>().expect("Pool not found in context");
-
-        let attendance = sqlx::query_as::<_, Attendance>(
-            "INSERT INTO Attendance (id, date, timein, timeout) VALUES ($1, $2, $3, $4) RETURNING *"
-        )
-        .bind(id)
-        .bind(date)
-        .bind(timein)
-        .bind(timeout)
-        .fetch_one(pool.as_ref())
-        .await?;
+        let store = ctx.data::<Arc<dyn Store>>().expect("Store not found in context");
 
-        Ok(attendance)
+        store
+            .add_attendance(NewAttendance {
+                id,
+                date,
+                timein,
+                timeout,
+            })
+            .await
     }
 }
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use async_graphql::{Context, Object};
+
+use crate::db::leaderboard::Leaderboard;
+use crate::leaderboard::manager::LeaderboardManager;
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Returns the top `limit` leaderboard entries, highest `unified_score`
+    /// first, straight from the in-memory cache.
+    async fn leaderboard(&self, ctx: &Context<'_>, limit: i32) -> Vec<Leaderboard> {
+        let manager = ctx
+            .data::<Arc<LeaderboardManager>>()
+            .expect("LeaderboardManager not found in context");
+
+        manager.top(limit.max(0) as usize).await
+    }
+
+    /// Returns `member_id`'s 0-indexed leaderboard rank, or `None` if they
+    /// don't have a leaderboard entry yet.
+    async fn member_rank(&self, ctx: &Context<'_>, member_id: i32) -> Option<i32> {
+        let manager = ctx
+            .data::<Arc<LeaderboardManager>>()
+            .expect("LeaderboardManager not found in context");
+
+        manager.rank_of(member_id).await.map(|rank| rank as i32)
+    }
+}
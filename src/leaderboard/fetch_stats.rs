@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tracing::instrument;
+
+use crate::leaderboard::manager::LeaderboardManager;
+
+#[derive(Debug, Deserialize)]
+struct LeetcodeStatsResponse {
+    #[serde(rename = "totalSolved")]
+    total_solved: i32,
+    #[serde(rename = "easySolved")]
+    easy_solved: i32,
+    #[serde(rename = "mediumSolved")]
+    medium_solved: i32,
+    #[serde(rename = "hardSolved")]
+    hard_solved: i32,
+    #[serde(rename = "contestAttend", default)]
+    contests_participated: i32,
+    #[serde(rename = "contestTopPercentage", default)]
+    contest_top_percentage: f64,
+}
+
+/// Fetches a member's public LeetCode stats, persists them, and refreshes
+/// their leaderboard entry through `manager` so the cache doesn't go stale
+/// waiting for the next full [`crate::leaderboard::update_leaderboard::update_leaderboard`] pass.
+#[instrument(skip(manager))]
+pub async fn fetch_leetcode_stats(
+    manager: Arc<LeaderboardManager>,
+    member_id: i32,
+    leetcode_username: &str,
+) -> Result<(), sqlx::Error> {
+    let url = format!("https://leetcode-stats-api.herokuapp.com/{leetcode_username}");
+
+    let stats: LeetcodeStatsResponse = reqwest::get(&url)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "failed to fetch LeetCode stats");
+            sqlx::Error::Io(std::io::Error::new(std::io::ErrorKind::Other, e))
+        })?
+        .json()
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "failed to parse LeetCode stats response");
+            sqlx::Error::Io(std::io::Error::new(std::io::ErrorKind::Other, e))
+        })?;
+
+    // Lower percentage means a better contest rank, so best_rank is stored
+    // as an integer percentile rather than an absolute rank the public API
+    // doesn't expose.
+    let best_rank = stats.contest_top_percentage.round() as i32;
+
+    manager
+        .store()
+        .upsert_leetcode_stats(
+            member_id,
+            leetcode_username,
+            stats.total_solved,
+            stats.easy_solved,
+            stats.medium_solved,
+            stats.hard_solved,
+            stats.contests_participated,
+            best_rank,
+            stats.contests_participated,
+        )
+        .await?;
+
+    manager.refresh_member(member_id).await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Deserialize)]
+struct CodeforcesApiResponse {
+    result: Vec<CodeforcesUserInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CodeforcesUserInfo {
+    #[serde(default)]
+    rating: i32,
+    #[serde(rename = "maxRating", default)]
+    max_rating: i32,
+}
+
+/// Fetches a member's public Codeforces stats, persists them, and refreshes
+/// their leaderboard entry through `manager`.
+#[instrument(skip(manager))]
+pub async fn fetch_codeforces_stats(
+    manager: Arc<LeaderboardManager>,
+    member_id: i32,
+    codeforces_handle: &str,
+) -> Result<(), sqlx::Error> {
+    let url = format!("https://codeforces.com/api/user.info?handles={codeforces_handle}");
+
+    let response: CodeforcesApiResponse = reqwest::get(&url)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "failed to fetch Codeforces stats");
+            sqlx::Error::Io(std::io::Error::new(std::io::ErrorKind::Other, e))
+        })?
+        .json()
+        .await
+        .map_err(|e| {
+            tracing::error!(error = %e, "failed to parse Codeforces stats response");
+            sqlx::Error::Io(std::io::Error::new(std::io::ErrorKind::Other, e))
+        })?;
+
+    let info = response.result.into_iter().next().ok_or_else(|| {
+        tracing::error!("Codeforces API returned no user for handle");
+        sqlx::Error::RowNotFound
+    })?;
+
+    // The public API doesn't expose a contest count directly; rating history
+    // would need a second call, so this is refined once that's wired up.
+    let contests_participated = 0;
+
+    manager
+        .store()
+        .upsert_codeforces_stats(
+            member_id,
+            codeforces_handle,
+            info.rating,
+            info.max_rating,
+            contests_participated,
+        )
+        .await?;
+
+    manager.refresh_member(member_id).await?;
+
+    Ok(())
+}
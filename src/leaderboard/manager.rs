@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::db::leaderboard::Leaderboard;
+use crate::db::member::Member;
+use crate::db::store::{MemberOnboarding, Store};
+
+struct LeaderboardState {
+    entries: Vec<Leaderboard>,
+    index: HashMap<i32, usize>,
+}
+
+impl LeaderboardState {
+    fn new(mut entries: Vec<Leaderboard>) -> Self {
+        entries.sort_by(|a, b| b.unified_score.cmp(&a.unified_score));
+        let index = entries
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.member_id, i))
+            .collect();
+        Self { entries, index }
+    }
+
+    /// Replaces (or inserts) `entry` and slides it to the position its
+    /// `unified_score` belongs at, re-indexing only the rows from the lower
+    /// of the old and new positions onward rather than re-sorting the whole
+    /// vector.
+    fn put(&mut self, entry: Leaderboard) {
+        let old_pos = self.index.remove(&entry.member_id);
+        if let Some(pos) = old_pos {
+            self.entries.remove(pos);
+        }
+
+        let new_pos = self
+            .entries
+            .partition_point(|e| e.unified_score > entry.unified_score);
+        self.entries.insert(new_pos, entry);
+
+        let from = old_pos.map_or(new_pos, |pos| pos.min(new_pos));
+        for (i, e) in self.entries.iter().enumerate().skip(from) {
+            self.index.insert(e.member_id, i);
+        }
+    }
+
+    /// Removes `member_id`'s entry, if it has one, and reindexes everything
+    /// after its old position.
+    fn remove(&mut self, member_id: i32) {
+        let Some(pos) = self.index.remove(&member_id) else {
+            return;
+        };
+        self.entries.remove(pos);
+        for (i, e) in self.entries.iter().enumerate().skip(pos) {
+            self.index.insert(e.member_id, i);
+        }
+    }
+}
+
+/// Keeps the leaderboard in memory so reads don't hit Postgres on every
+/// GraphQL query. Every write goes through [`LeaderboardManager::upsert`] or
+/// [`LeaderboardManager::evict`], each of which changes the row and the
+/// cache under the same lock so the two never drift apart.
+pub struct LeaderboardManager {
+    store: Arc<dyn Store>,
+    state: RwLock<LeaderboardState>,
+}
+
+impl LeaderboardManager {
+    /// Loads every active member's leaderboard row from `store` into memory.
+    pub async fn load(store: Arc<dyn Store>) -> Result<Self, sqlx::Error> {
+        let entries = store.leaderboard().await?;
+        Ok(Self {
+            store,
+            state: RwLock::new(LeaderboardState::new(entries)),
+        })
+    }
+
+    pub fn store(&self) -> &Arc<dyn Store> {
+        &self.store
+    }
+
+    /// Onboards a member through the store, then immediately upserts their
+    /// zeroed leaderboard row into the cache so `top`/`rank_of`/`snapshot`
+    /// see the new member right away instead of waiting for the next
+    /// [`crate::leaderboard::update_leaderboard::update_leaderboard`] pass.
+    pub async fn onboard_member(
+        &self,
+        onboarding: MemberOnboarding,
+    ) -> Result<Member, sqlx::Error> {
+        let member = self.store.onboard_member(onboarding).await?;
+        self.upsert(member.id, Some(0), Some(0), 0).await?;
+        Ok(member)
+    }
+
+    /// Writes a leaderboard row to the database and updates the in-memory
+    /// copy while still holding the write lock.
+    pub async fn upsert(
+        &self,
+        member_id: i32,
+        leetcode_score: Option<i32>,
+        codeforces_score: Option<i32>,
+        unified_score: i32,
+    ) -> Result<Leaderboard, sqlx::Error> {
+        let mut state = self.state.write().await;
+        let entry = self
+            .store
+            .upsert_leaderboard_entry(member_id, leetcode_score, codeforces_score, unified_score)
+            .await?;
+        state.put(entry.clone());
+        Ok(entry)
+    }
+
+    /// Retires `member_id`'s leaderboard row: deletes it from the store and
+    /// drops it from the cache under the same lock. Used by
+    /// [`crate::leaderboard::update_leaderboard::update_leaderboard`] to
+    /// reconcile members who've gone inactive since they were last cached, so
+    /// their frozen score doesn't linger on the leaderboard forever.
+    pub async fn evict(&self, member_id: i32) -> Result<(), sqlx::Error> {
+        let mut state = self.state.write().await;
+        self.store.delete_leaderboard_entry(member_id).await?;
+        state.remove(member_id);
+        Ok(())
+    }
+
+    /// Recomputes `member_id`'s unified score from whatever LeetCode/
+    /// Codeforces stats are currently on record and upserts it. Used after
+    /// a single provider's stats are refreshed so the leaderboard doesn't
+    /// have to wait for a full [`crate::leaderboard::update_leaderboard::update_leaderboard`] pass.
+    pub async fn refresh_member(&self, member_id: i32) -> Result<Leaderboard, sqlx::Error> {
+        let leetcode = self.store.leetcode_stats_for_member(member_id).await?;
+        let codeforces = self.store.codeforces_stats_for_member(member_id).await?;
+
+        let leetcode_score = leetcode.map(|s| s.problems_solved);
+        let codeforces_score = codeforces.map(|s| s.codeforces_rating);
+        let unified_score = leetcode_score.unwrap_or(0) + codeforces_score.unwrap_or(0);
+
+        self.upsert(member_id, leetcode_score, codeforces_score, unified_score)
+            .await
+    }
+
+    /// Returns the top `n` entries, highest `unified_score` first.
+    pub async fn top(&self, n: usize) -> Vec<Leaderboard> {
+        let state = self.state.read().await;
+        state.entries.iter().take(n).cloned().collect()
+    }
+
+    /// Returns `member_id`'s 0-indexed rank, if they're on the leaderboard.
+    pub async fn rank_of(&self, member_id: i32) -> Option<usize> {
+        let state = self.state.read().await;
+        state.index.get(&member_id).copied()
+    }
+
+    /// Returns the whole leaderboard, highest `unified_score` first.
+    pub async fn snapshot(&self) -> Vec<Leaderboard> {
+        let state = self.state.read().await;
+        state.entries.clone()
+    }
+}
@@ -0,0 +1,4 @@
+pub mod fetch_stats;
+pub mod manager;
+pub mod runner;
+pub mod update_leaderboard;
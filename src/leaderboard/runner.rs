@@ -0,0 +1,114 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::stream::{self, StreamExt};
+
+use crate::leaderboard::fetch_stats::{fetch_codeforces_stats, fetch_leetcode_stats};
+use crate::leaderboard::manager::LeaderboardManager;
+use crate::leaderboard::update_leaderboard::update_leaderboard;
+use crate::sync::roster::{sync_members, RosterSyncConfig};
+
+/// Tunables for [`refresh_all`] and [`run_refresh_loop`].
+#[derive(Debug, Clone)]
+pub struct RunnerConfig {
+    pub interval: Duration,
+    /// Max number of LeetCode/Codeforces fetches in flight at once, per provider.
+    pub concurrency: usize,
+    /// When set, the roster is synced before stats are refreshed so newly
+    /// onboarded/deactivated members are reflected in this pass.
+    pub roster: Option<RosterSyncConfig>,
+}
+
+/// Per-provider success/failure counts from one [`refresh_all`] pass.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RefreshMetrics {
+    pub leetcode_ok: usize,
+    pub leetcode_failed: usize,
+    pub codeforces_ok: usize,
+    pub codeforces_failed: usize,
+}
+
+/// Syncs the roster (if configured), then refreshes every active member's
+/// LeetCode and Codeforces stats with at most `config.concurrency` requests
+/// per provider in flight (members with an empty handle for a provider are
+/// skipped for that provider), and rebuilds the leaderboard from the results. An
+/// individual member's fetch failing is recorded in the returned metrics
+/// rather than aborting the rest of the run.
+#[tracing::instrument(skip(manager, config))]
+pub async fn refresh_all(
+    manager: Arc<LeaderboardManager>,
+    config: &RunnerConfig,
+) -> Result<RefreshMetrics, sqlx::Error> {
+    if let Some(roster) = &config.roster {
+        sync_members(manager.store().clone(), roster).await?;
+    }
+
+    let leetcode_stats = manager.store().all_leetcode_stats().await?;
+    let codeforces_stats = manager.store().all_codeforces_stats().await?;
+
+    let leetcode_results: Vec<Result<(), sqlx::Error>> = stream::iter(
+        leetcode_stats
+            .into_iter()
+            .filter(|stats| !stats.leetcode_username.is_empty()),
+    )
+    .map(|stats| {
+        let manager = manager.clone();
+        async move { fetch_leetcode_stats(manager, stats.member_id, &stats.leetcode_username).await }
+    })
+    .buffer_unordered(config.concurrency)
+    .collect()
+    .await;
+
+    let codeforces_results: Vec<Result<(), sqlx::Error>> = stream::iter(
+        codeforces_stats
+            .into_iter()
+            .filter(|stats| !stats.codeforces_handle.is_empty()),
+    )
+    .map(|stats| {
+        let manager = manager.clone();
+        async move {
+            fetch_codeforces_stats(manager, stats.member_id, &stats.codeforces_handle).await
+        }
+    })
+    .buffer_unordered(config.concurrency)
+    .collect()
+    .await;
+
+    let mut metrics = RefreshMetrics::default();
+    for result in leetcode_results {
+        match result {
+            Ok(()) => metrics.leetcode_ok += 1,
+            Err(e) => {
+                tracing::error!(error = %e, "LeetCode stats refresh failed for a member");
+                metrics.leetcode_failed += 1;
+            }
+        }
+    }
+    for result in codeforces_results {
+        match result {
+            Ok(()) => metrics.codeforces_ok += 1,
+            Err(e) => {
+                tracing::error!(error = %e, "Codeforces stats refresh failed for a member");
+                metrics.codeforces_failed += 1;
+            }
+        }
+    }
+
+    update_leaderboard(manager).await?;
+
+    Ok(metrics)
+}
+
+/// Runs [`refresh_all`] on `config.interval`, logging (rather than aborting
+/// the loop on) a failed pass so a bad run doesn't take down the whole
+/// process.
+pub async fn run_refresh_loop(manager: Arc<LeaderboardManager>, config: RunnerConfig) {
+    let mut interval = tokio::time::interval(config.interval);
+    loop {
+        interval.tick().await;
+        match refresh_all(manager.clone(), &config).await {
+            Ok(metrics) => tracing::info!(?metrics, "leaderboard refresh complete"),
+            Err(e) => tracing::error!(error = %e, "leaderboard refresh failed"),
+        }
+    }
+}
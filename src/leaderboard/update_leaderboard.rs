@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tracing::instrument;
+
+use crate::leaderboard::manager::LeaderboardManager;
+
+/// Recomputes the unified score for every active member that has LeetCode
+/// and/or Codeforces stats on record, and upserts each one through `manager` so
+/// the database row and the in-memory cache stay in lockstep. Any cached
+/// entry that's no longer in that active set (e.g. a member `sync_roster`
+/// has since marked inactive) is evicted, so a deactivated member's frozen
+/// score doesn't linger on the leaderboard forever.
+#[instrument(skip(manager))]
+pub async fn update_leaderboard(manager: Arc<LeaderboardManager>) -> Result<(), sqlx::Error> {
+    let leetcode_stats = manager.store().all_leetcode_stats().await?;
+    let codeforces_stats = manager.store().all_codeforces_stats().await?;
+
+    let mut scores: HashMap<i32, (Option<i32>, Option<i32>)> = HashMap::new();
+    for stats in leetcode_stats {
+        scores.entry(stats.member_id).or_default().0 = Some(stats.problems_solved);
+    }
+    for stats in codeforces_stats {
+        scores.entry(stats.member_id).or_default().1 = Some(stats.codeforces_rating);
+    }
+
+    let mut evicted_count = 0;
+    for entry in manager.snapshot().await {
+        if !scores.contains_key(&entry.member_id) {
+            manager.evict(entry.member_id).await?;
+            evicted_count += 1;
+        }
+    }
+
+    let member_count = scores.len();
+    for (member_id, (leetcode_score, codeforces_score)) in scores {
+        let unified_score = leetcode_score.unwrap_or(0) + codeforces_score.unwrap_or(0);
+        if let Err(e) = manager
+            .upsert(member_id, leetcode_score, codeforces_score, unified_score)
+            .await
+        {
+            tracing::error!(member_id, error = %e, "failed to upsert leaderboard entry");
+            return Err(e);
+        }
+    }
+
+    tracing::info!(member_count, evicted_count, "leaderboard rebuilt");
+
+    Ok(())
+}
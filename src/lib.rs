@@ -0,0 +1,4 @@
+pub mod db;
+pub mod graphql;
+pub mod leaderboard;
+pub mod sync;
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::db::member::RemoteMember;
+use crate::db::store::Store;
+
+/// Where to fetch the authoritative member roster from, and how often.
+#[derive(Debug, Clone)]
+pub struct RosterSyncConfig {
+    pub roster_url: String,
+    pub interval: Duration,
+}
+
+/// Fetches the roster from `config.roster_url` and reconciles it into
+/// `store` in one pass: known members are updated, newcomers are inserted,
+/// and anyone missing from the response is marked inactive.
+pub async fn sync_members(
+    store: Arc<dyn Store>,
+    config: &RosterSyncConfig,
+) -> Result<(), sqlx::Error> {
+    let roster: Vec<RemoteMember> = reqwest::get(&config.roster_url)
+        .await
+        .map_err(|e| sqlx::Error::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?
+        .json()
+        .await
+        .map_err(|e| sqlx::Error::Io(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
+
+    store.sync_roster(roster).await
+}
+
+/// Runs [`sync_members`] on `config.interval`, logging (rather than
+/// aborting the loop on) individual sync failures so a transient roster API
+/// outage doesn't take down the whole process.
+pub async fn run_sync_loop(store: Arc<dyn Store>, config: RosterSyncConfig) {
+    let mut interval = tokio::time::interval(config.interval);
+    loop {
+        interval.tick().await;
+        if let Err(e) = sync_members(store.clone(), &config).await {
+            tracing::error!(error = ?e, "member roster sync failed");
+        }
+    }
+}
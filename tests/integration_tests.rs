@@ -1,141 +1,43 @@
 use root::db::leaderboard::Leaderboard;
-use root::db::member::Member;
+use root::db::member::{Member, RemoteMember};
+use root::db::store::{MemberOnboarding, NewMember, SqliteStore, Store};
 use root::leaderboard::fetch_stats::{fetch_codeforces_stats, fetch_leetcode_stats};
+use root::leaderboard::manager::LeaderboardManager;
+use root::leaderboard::runner::{refresh_all, RunnerConfig};
 use root::leaderboard::update_leaderboard::update_leaderboard;
-use sqlx::{postgres::PgPoolOptions, PgPool};
-use std::env;
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::SqlitePool;
 use std::sync::Arc;
 
-pub fn get_database_url() -> String {
-    match env::var("TEST_DATABASE_URL") {
-        Ok(db_url) => db_url,
-        Err(_) => "postgres://localhost:5432/default_db".to_string(),
-    }
-}
-
-// Helper function to create a test database connection
-async fn setup_test_db() -> PgPool {
-    let database_url = get_database_url();
-    let pool = PgPoolOptions::new()
+// Helper function to create an in-memory SQLite database for tests, so the
+// suite doesn't need a live Postgres instance. Applies the same versioned
+// migrations under migrations/sqlite/ that SqliteStore::connect runs (the
+// SQLite-syntax mirror of what PostgresStore::connect applies), instead of
+// duplicating the schema as hand-written DDL that could drift from it.
+async fn setup_test_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
         .max_connections(5)
-        .connect(&database_url)
+        .connect("sqlite::memory:")
         .await
         .expect("Failed to create test database pool");
 
-    // Create tables if they do not already exist
-    let queries = vec![
-        r#"
-        CREATE TABLE IF NOT EXISTS member (
-            id SERIAL PRIMARY KEY,
-            rollno VARCHAR(255) NOT NULL,
-            name VARCHAR(255) NOT NULL,
-            hostel VARCHAR(255) NOT NULL,
-            email VARCHAR(255) NOT NULL UNIQUE,
-            sex VARCHAR(10) NOT NULL,
-            year INT NOT NULL,
-            macaddress VARCHAR(17) NOT NULL,
-            discord_id VARCHAR(255),
-            group_id INT NOT NULL
-        )"#,
-        r#"
-        CREATE TABLE IF NOT EXISTS leaderboard (
-            id SERIAL PRIMARY KEY,
-            member_id INT UNIQUE NOT NULL,
-            leetcode_score INT,
-            codeforces_score INT,
-            unified_score INT NOT NULL,
-            last_updated TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
-            FOREIGN KEY (member_id) REFERENCES member(id)
-        )"#,
-        r#"
-        CREATE TABLE IF NOT EXISTS leetcode_stats (
-            id SERIAL PRIMARY KEY,
-            member_id INT UNIQUE NOT NULL,
-            leetcode_username VARCHAR(255) NOT NULL,
-            problems_solved INT NOT NULL,
-            easy_solved INT NOT NULL,
-            medium_solved INT NOT NULL,
-            hard_solved INT NOT NULL,
-            contests_participated INT NOT NULL,
-            best_rank INT NOT NULL,
-            total_contests INT NOT NULL,
-            FOREIGN KEY (member_id) REFERENCES member(id)
-        )"#,
-        r#"
-        CREATE TABLE IF NOT EXISTS codeforces_stats (
-            id SERIAL PRIMARY KEY,
-            member_id INT UNIQUE NOT NULL,
-            codeforces_handle VARCHAR(255) NOT NULL,
-            codeforces_rating INT NOT NULL,
-            max_rating INT NOT NULL,
-            contests_participated INT NOT NULL,
-            FOREIGN KEY (member_id) REFERENCES member(id)
-        )"#,
-    ];
-
-    for query in queries {
-        sqlx::query(query)
-            .execute(&pool)
-            .await
-            .expect("Failed to execute query");
-    }
-    pool
-}
-
-// Helper function to clean up test data
-
-async fn cleanup_test_data(pool: &PgPool) {
-    print!("called");
-    let cleanup_query = r#"
-        DO $$
-        DECLARE
-            seq RECORD;
-        BEGIN
-            -- Droppign all the tables for cleanup purpose
-            BEGIN
-                TRUNCATE TABLE leaderboard, leetcode_stats, codeforces_stats, member RESTART IDENTITY CASCADE;
-            EXCEPTION
-                WHEN undefined_table THEN
-                    -- Ignore errors if tables don't exist
-                    RAISE NOTICE 'Tables do not exist, skipping TRUNCATE.';
-            END;
-
-            -- Postgres stores the sequences of unique id outside of respective tables, so need to delete those too. 
-            FOR seq IN
-                SELECT c.relname
-                FROM pg_class c
-                JOIN pg_namespace n ON n.oid = c.relnamespace
-                WHERE c.relkind = 'S' AND n.nspname = 'public'
-            LOOP
-                BEGIN
-                    EXECUTE 'ALTER SEQUENCE ' || seq.relname || ' RESTART WITH 1';
-                EXCEPTION
-                    WHEN undefined_object THEN
-                        -- Ignore errors if sequences don't exist
-                        RAISE NOTICE 'Sequence % does not exist, skipping.', seq.relname;
-                END;
-            END LOOP;
-        END $$;
-    "#;
-
-    sqlx::query(cleanup_query)
-        .execute(pool)
+    sqlx::migrate!("./migrations/sqlite")
+        .run(&pool)
         .await
-        .expect("Failed to clean up and reset database state");
-}
+        .expect("Failed to run migrations");
 
-#[tokio::test]
-// Additional helper test to verify database connections and basic operations
-async fn test_database_connection() {
-    let database_url = get_database_url();
-    println!("Database URL: {}", database_url);
-    assert!(!database_url.is_empty(), "Database URL should not be empty");
+    pool
 }
 
-//test
 #[tokio::test]
 async fn test_insert_members_and_update_stats() {
     let pool = setup_test_db().await;
+    let store: Arc<dyn Store> = Arc::new(SqliteStore::new(pool.clone()));
+    let manager = Arc::new(
+        LeaderboardManager::load(store.clone())
+            .await
+            .expect("Failed to load leaderboard manager"),
+    );
 
     // Define test members
     let members = vec![
@@ -146,9 +48,6 @@ async fn test_insert_members_and_update_stats() {
             "john.doe@example.com",
             "Male",
             2021,
-            "00:11:22:33:44:55",
-            Some("john_discord"),
-            1,
             "swayam-agrahari",
             "tourist",
         ),
@@ -159,68 +58,41 @@ async fn test_insert_members_and_update_stats() {
             "jane.smith@example.com",
             "Female",
             2021,
-            "66:77:88:99:AA:BB",
-            Some("jane_discord"),
-            2,
             "rihaan1810",
             "tourist",
         ),
     ];
 
-    let mut inserted_members = Vec::new();
+    let mut inserted_members: Vec<Member> = Vec::new();
 
-    // Insert members and store their IDs
+    // Onboard members: member + leetcode_stats + codeforces_stats +
+    // leaderboard rows are inserted atomically in one transaction.
     for member in &members {
-        // Insert Member
-        let member_result = sqlx::query_as::<_, Member>(
-            "INSERT INTO member (rollno, name, hostel, email, sex, year, macaddress, discord_id, group_id)
-                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-                 RETURNING *",
-        )
-        .bind(&member.0)
-        .bind(&member.1)
-        .bind(&member.2)
-        .bind(&member.3)
-        .bind(&member.4)
-        .bind(member.5)
-        .bind(&member.6)
-        .bind(&member.7)
-        .bind(&member.8)
-        .fetch_one(&pool)
-        .await
-        .expect("Failed to insert member");
-
-        // Insert LeetCode stats
-        let _leetcode_result = sqlx::query(
-                "INSERT INTO leetcode_stats (member_id, leetcode_username,problems_solved,easy_solved,medium_solved,hard_solved,contests_participated,best_rank,total_contests)
-                 VALUES ($1, $2, 0,0,0,0,0,0,0)",
-            )
-            .bind(member_result.id)
-            .bind(&member.9)
-            .execute(&pool)
-            .await
-            .expect("Failed to insert LeetCode stats");
-
-        // Insert Codeforces stats
-        let _codeforces_result = sqlx::query(
-                "INSERT INTO codeforces_stats (member_id, codeforces_handle,codeforces_rating,max_rating,contests_participated)
-                 VALUES ($1, $2, 0,0,0)",
-            )
-            .bind(member_result.id)
-            .bind(&member.10)
-            .execute(&pool)
+        let member_result = store
+            .onboard_member(MemberOnboarding {
+                member: NewMember {
+                    rollno: member.0.to_string(),
+                    name: member.1.to_string(),
+                    hostel: member.2.to_string(),
+                    email: member.3.to_string(),
+                    sex: member.4.to_string(),
+                    year: member.5,
+                },
+                leetcode_username: Some(member.6.to_string()),
+                codeforces_handle: Some(member.7.to_string()),
+            })
             .await
-            .expect("Failed to insert Codeforces stats");
+            .expect("Failed to onboard member");
 
-        inserted_members.push(member_result.id);
+        inserted_members.push(member_result);
     }
 
     // Test LeetCode stats fetching
-    for (member_id, leetcode_username) in inserted_members.iter().zip(members.iter().map(|m| m.9)) {
-        match fetch_leetcode_stats(Arc::new(pool.clone()), *member_id, leetcode_username).await {
+    for (member, leetcode_username) in inserted_members.iter().zip(members.iter().map(|m| m.6)) {
+        match fetch_leetcode_stats(manager.clone(), member.id, leetcode_username).await {
             Ok(_) => println!(
                 "Successfully fetched LeetCode stats for member ID: {}",
-                member_id
+                member.id
             ),
             Err(e) => {
                 println!("Error fetching LeetCode stats: {:?}", e);
@@ -231,11 +103,11 @@ async fn test_insert_members_and_update_stats() {
     }
 
     // Test Codeforces stats fetching
-    for (member_id, codeforces_handle) in inserted_members.iter().zip(members.iter().map(|m| m.9)) {
-        match fetch_codeforces_stats(Arc::new(pool.clone()), *member_id, codeforces_handle).await {
+    for (member, codeforces_handle) in inserted_members.iter().zip(members.iter().map(|m| m.7)) {
+        match fetch_codeforces_stats(manager.clone(), member.id, codeforces_handle).await {
             Ok(_) => println!(
                 "Successfully fetched Codeforces stats for member ID: {}",
-                member_id
+                member.id
             ),
             Err(e) => {
                 println!("Error fetching Codeforces stats: {:?}", e);
@@ -244,16 +116,13 @@ async fn test_insert_members_and_update_stats() {
     }
 
     // Test leaderboard update
-    match update_leaderboard(Arc::new(pool.clone())).await {
+    match update_leaderboard(manager.clone()).await {
         Ok(_) => println!("Successfully updated leaderboard"),
         Err(e) => panic!("Failed to update leaderboard: {:?}", e),
     }
 
-    // Verify leaderboard entries
-    let leaderboard_entries = sqlx::query_as::<_, Leaderboard>("SELECT * FROM leaderboard")
-        .fetch_all(&pool)
-        .await
-        .unwrap();
+    // Verify leaderboard entries, read straight from the in-memory cache
+    let leaderboard_entries: Vec<Leaderboard> = manager.snapshot().await;
 
     assert_eq!(
         leaderboard_entries.len(),
@@ -261,7 +130,6 @@ async fn test_insert_members_and_update_stats() {
         "Should have 2 leaderboard entries"
     );
 
-    // Assertions about leaderboard scores
     for entry in leaderboard_entries {
         assert!(entry.unified_score > 0, "Unified score should be positive");
         assert!(
@@ -273,6 +141,357 @@ async fn test_insert_members_and_update_stats() {
             "Codeforces score should be set"
         );
     }
+}
+
+#[tokio::test]
+async fn test_leaderboard_manager_top_and_rank() {
+    let pool = setup_test_db().await;
+    let store: Arc<dyn Store> = Arc::new(SqliteStore::new(pool.clone()));
+
+    let mut member_ids = Vec::new();
+    for (rollno, email) in [
+        ("B21CS0001", "low@example.com"),
+        ("B21CS0002", "mid@example.com"),
+        ("B21CS0003", "high@example.com"),
+    ] {
+        let member = store
+            .add_member(NewMember {
+                rollno: rollno.to_string(),
+                name: rollno.to_string(),
+                hostel: "Hostel A".to_string(),
+                email: email.to_string(),
+                sex: "Male".to_string(),
+                year: 2021,
+            })
+            .await
+            .expect("Failed to add member");
+        member_ids.push(member.id);
+    }
+
+    // Seed leaderboard rows directly with distinct, out-of-order scores so
+    // ordering and rank aren't incidentally correct.
+    for (member_id, unified_score) in [
+        (member_ids[0], 10),
+        (member_ids[1], 30),
+        (member_ids[2], 20),
+    ] {
+        store
+            .upsert_leaderboard_entry(member_id, Some(unified_score), Some(0), unified_score)
+            .await
+            .expect("Failed to seed leaderboard entry");
+    }
+
+    let manager = LeaderboardManager::load(store.clone())
+        .await
+        .expect("Failed to load leaderboard manager");
+
+    let top_two = manager.top(2).await;
+    assert_eq!(top_two.len(), 2, "top(2) should return 2 entries");
+    assert_eq!(top_two[0].member_id, member_ids[1], "highest score first");
+    assert_eq!(top_two[1].member_id, member_ids[2], "second-highest next");
+
+    assert_eq!(manager.rank_of(member_ids[1]).await, Some(0));
+    assert_eq!(manager.rank_of(member_ids[2]).await, Some(1));
+    assert_eq!(manager.rank_of(member_ids[0]).await, Some(2));
+    assert_eq!(manager.rank_of(-1).await, None, "unknown member has no rank");
+}
+
+#[tokio::test]
+async fn test_sync_roster_reconciles_members() {
+    let pool = setup_test_db().await;
+    let store: Arc<dyn Store> = Arc::new(SqliteStore::new(pool.clone()));
+
+    let kept = store
+        .add_member(NewMember {
+            rollno: "B21CS0010".to_string(),
+            name: "Kept Member".to_string(),
+            hostel: "Hostel A".to_string(),
+            email: "kept@example.com".to_string(),
+            sex: "Male".to_string(),
+            year: 2021,
+        })
+        .await
+        .expect("Failed to add member");
+
+    let dropped = store
+        .add_member(NewMember {
+            rollno: "B21CS0011".to_string(),
+            name: "Dropped Member".to_string(),
+            hostel: "Hostel A".to_string(),
+            email: "dropped@example.com".to_string(),
+            sex: "Male".to_string(),
+            year: 2021,
+        })
+        .await
+        .expect("Failed to add member");
 
-    cleanup_test_data(&pool).await;
+    // The roster only lists `kept` (with an updated name) and a newcomer;
+    // `dropped` is missing and should be marked inactive, not deleted.
+    store
+        .sync_roster(vec![
+            RemoteMember {
+                rollno: "B21CS0010".to_string(),
+                name: "Kept Member Renamed".to_string(),
+                hostel: "Hostel B".to_string(),
+                email: "kept@example.com".to_string(),
+                year: 2022,
+                discord_id: Some("kept#0001".to_string()),
+            },
+            RemoteMember {
+                rollno: "B21CS0012".to_string(),
+                name: "New Member".to_string(),
+                hostel: "Hostel C".to_string(),
+                email: "new@example.com".to_string(),
+                year: 2023,
+                discord_id: None,
+            },
+        ])
+        .await
+        .expect("Failed to sync roster");
+
+    let active_emails: Vec<String> = {
+        let rows: Vec<Member> = sqlx::query_as("SELECT * FROM member WHERE active")
+            .fetch_all(&pool)
+            .await
+            .expect("Failed to query active members");
+        rows.into_iter().map(|m| m.email).collect()
+    };
+    assert!(active_emails.contains(&"kept@example.com".to_string()));
+    assert!(active_emails.contains(&"new@example.com".to_string()));
+    assert!(!active_emails.contains(&"dropped@example.com".to_string()));
+
+    let kept_row: Member = sqlx::query_as("SELECT * FROM member WHERE id = ?")
+        .bind(kept.id)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to fetch kept member");
+    assert_eq!(kept_row.name, "Kept Member Renamed");
+    assert_eq!(kept_row.year, 2022);
+    assert_eq!(kept_row.discord_id, Some("kept#0001".to_string()));
+
+    let dropped_row: Member = sqlx::query_as("SELECT * FROM member WHERE id = ?")
+        .bind(dropped.id)
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to fetch dropped member");
+    assert!(!dropped_row.active, "member missing from roster goes inactive");
+}
+
+#[tokio::test]
+async fn test_refresh_all_skips_inactive_members() {
+    let pool = setup_test_db().await;
+    let store: Arc<dyn Store> = Arc::new(SqliteStore::new(pool.clone()));
+    let manager = Arc::new(
+        LeaderboardManager::load(store.clone())
+            .await
+            .expect("Failed to load leaderboard manager"),
+    );
+
+    let active_member = store
+        .onboard_member(MemberOnboarding {
+            member: NewMember {
+                rollno: "B21CS0020".to_string(),
+                name: "Active Member".to_string(),
+                hostel: "Hostel A".to_string(),
+                email: "active@example.com".to_string(),
+                sex: "Male".to_string(),
+                year: 2021,
+            },
+            leetcode_username: Some("swayam-agrahari".to_string()),
+            codeforces_handle: Some("tourist".to_string()),
+        })
+        .await
+        .expect("Failed to onboard active member");
+
+    store
+        .onboard_member(MemberOnboarding {
+            member: NewMember {
+                rollno: "B21CS0021".to_string(),
+                name: "Inactive Member".to_string(),
+                hostel: "Hostel A".to_string(),
+                email: "inactive@example.com".to_string(),
+                sex: "Male".to_string(),
+                year: 2021,
+            },
+            leetcode_username: Some("rihaan1810".to_string()),
+            codeforces_handle: Some("tourist".to_string()),
+        })
+        .await
+        .expect("Failed to onboard inactive member");
+
+    // Drop the second member from the roster so they're marked inactive
+    // before the refresh runs.
+    store
+        .sync_roster(vec![RemoteMember {
+            rollno: active_member.rollno.clone(),
+            name: active_member.name.clone(),
+            hostel: active_member.hostel.clone(),
+            email: active_member.email.clone(),
+            year: active_member.year,
+            discord_id: None,
+        }])
+        .await
+        .expect("Failed to sync roster");
+
+    let config = RunnerConfig {
+        interval: std::time::Duration::from_secs(3600),
+        concurrency: 2,
+        roster: None,
+    };
+
+    let metrics = refresh_all(manager.clone(), &config)
+        .await
+        .expect("refresh_all should not fail outright");
+
+    // Only the active member's stats should have been attempted at all;
+    // the inactive member must not show up in either count.
+    assert_eq!(
+        metrics.leetcode_ok + metrics.leetcode_failed,
+        1,
+        "only the active member's LeetCode stats should be attempted"
+    );
+    assert_eq!(
+        metrics.codeforces_ok + metrics.codeforces_failed,
+        1,
+        "only the active member's Codeforces stats should be attempted"
+    );
+
+    let leaderboard_entries = manager.snapshot().await;
+    assert_eq!(
+        leaderboard_entries.len(),
+        1,
+        "inactive member should not appear on the rebuilt leaderboard"
+    );
+    assert_eq!(leaderboard_entries[0].member_id, active_member.id);
+}
+
+#[tokio::test]
+async fn test_manager_onboard_member_updates_cache_immediately() {
+    let pool = setup_test_db().await;
+    let store: Arc<dyn Store> = Arc::new(SqliteStore::new(pool.clone()));
+    let manager = LeaderboardManager::load(store.clone())
+        .await
+        .expect("Failed to load leaderboard manager");
+
+    // Deliberately don't call fetch_leetcode_stats/fetch_codeforces_stats or
+    // update_leaderboard here — onboarding alone should be enough for the
+    // new member to show up in the cache.
+    let member = manager
+        .onboard_member(MemberOnboarding {
+            member: NewMember {
+                rollno: "B21CS0030".to_string(),
+                name: "Fresh Member".to_string(),
+                hostel: "Hostel A".to_string(),
+                email: "fresh@example.com".to_string(),
+                sex: "Male".to_string(),
+                year: 2021,
+            },
+            leetcode_username: Some("swayam-agrahari".to_string()),
+            codeforces_handle: Some("tourist".to_string()),
+        })
+        .await
+        .expect("Failed to onboard member through manager");
+
+    assert_eq!(
+        manager.rank_of(member.id).await,
+        Some(0),
+        "newly onboarded member should be ranked immediately"
+    );
+
+    let entries = manager.snapshot().await;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].member_id, member.id);
+    assert_eq!(entries[0].unified_score, 0);
+}
+
+#[tokio::test]
+async fn test_update_leaderboard_evicts_deactivated_member() {
+    let pool = setup_test_db().await;
+    let store: Arc<dyn Store> = Arc::new(SqliteStore::new(pool.clone()));
+
+    let active_member = store
+        .onboard_member(MemberOnboarding {
+            member: NewMember {
+                rollno: "B21CS0040".to_string(),
+                name: "Active Member".to_string(),
+                hostel: "Hostel A".to_string(),
+                email: "active40@example.com".to_string(),
+                sex: "Male".to_string(),
+                year: 2021,
+            },
+            leetcode_username: Some("swayam-agrahari".to_string()),
+            codeforces_handle: Some("tourist".to_string()),
+        })
+        .await
+        .expect("Failed to onboard active member");
+
+    let dropped_member = store
+        .onboard_member(MemberOnboarding {
+            member: NewMember {
+                rollno: "B21CS0041".to_string(),
+                name: "Soon Inactive Member".to_string(),
+                hostel: "Hostel A".to_string(),
+                email: "inactive41@example.com".to_string(),
+                sex: "Male".to_string(),
+                year: 2021,
+            },
+            leetcode_username: Some("rihaan1810".to_string()),
+            codeforces_handle: Some("tourist".to_string()),
+        })
+        .await
+        .expect("Failed to onboard soon-to-be-inactive member");
+
+    // Load the manager *after* both members are onboarded, so both land in
+    // the cache as a real, already-cached entry — not the empty-cache
+    // scenario `test_refresh_all_skips_inactive_members` exercises.
+    let manager = Arc::new(
+        LeaderboardManager::load(store.clone())
+            .await
+            .expect("Failed to load leaderboard manager"),
+    );
+    assert_eq!(manager.snapshot().await.len(), 2);
+
+    // Drop the second member from the roster so they're marked inactive
+    // after they're already cached.
+    store
+        .sync_roster(vec![RemoteMember {
+            rollno: active_member.rollno.clone(),
+            name: active_member.name.clone(),
+            hostel: active_member.hostel.clone(),
+            email: active_member.email.clone(),
+            year: active_member.year,
+            discord_id: None,
+        }])
+        .await
+        .expect("Failed to sync roster");
+
+    update_leaderboard(manager.clone())
+        .await
+        .expect("update_leaderboard should not fail");
+
+    let entries = manager.snapshot().await;
+    assert_eq!(
+        entries.len(),
+        1,
+        "deactivated member's stale entry should be evicted from the cache"
+    );
+    assert_eq!(entries[0].member_id, active_member.id);
+    assert_eq!(
+        manager.rank_of(dropped_member.id).await,
+        None,
+        "deactivated member should no longer have a rank"
+    );
+
+    // Read the raw table: `Store::leaderboard` already filters out inactive
+    // members, so it can't tell whether the row was actually deleted.
+    let stored_entries: Vec<Leaderboard> = sqlx::query_as("SELECT * FROM leaderboard")
+        .fetch_all(&pool)
+        .await
+        .expect("Failed to fetch stored leaderboard");
+    assert_eq!(
+        stored_entries.len(),
+        1,
+        "deactivated member's row should also be gone from the store"
+    );
+    assert_eq!(stored_entries[0].member_id, active_member.id);
 }